@@ -1,22 +1,56 @@
 use crate::U256;
 use alloy_primitives::U64;
 use eyre::Result;
-use serde::{Deserialize, Deserializer, Serialize};
-use std::{fmt, str::FromStr};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use std::{
+    collections::HashMap,
+    fmt,
+    str::FromStr,
+    sync::{OnceLock, RwLock},
+    time::Duration,
+};
 
 pub use ethers_core::types::Chain as NamedChain;
 
 /// Either a named or chain id or the actual id value
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
-#[serde(untagged)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Chain {
     /// Contains a known chain
-    #[serde(serialize_with = "super::from_str_lowercase::serialize")]
     Named(NamedChain),
     /// Contains the id of a chain
     Id(u64),
 }
 
+/// Converts a raw chain id into its [`NamedChain`], if any.
+///
+/// Every caller (`named`, `Display`, `From<u64>`, `TryFrom<Chain>`) resolves
+/// ids through this helper, a thin wrapper over [`NamedChain::try_from`], so
+/// they all agree on the same conversion. `TryFrom<Chain>` additionally calls
+/// [`NamedChain::try_from`] on a miss to obtain its typed error.
+fn named_chain_from_id(id: u64) -> Option<NamedChain> {
+    NamedChain::try_from(id).ok()
+}
+
+/// Maps common alternate spellings and historical names to their canonical
+/// [`NamedChain`], for chains [`NamedChain::from_str`] doesn't recognize
+/// directly under that spelling. `Display`/`Serialize` always emit the
+/// canonical name regardless of which alias was used to parse it.
+fn named_chain_alias(s: &str) -> Option<NamedChain> {
+    Some(match s.to_ascii_lowercase().as_str() {
+        "ethereum" | "eth" => NamedChain::Mainnet,
+        "matic" => NamedChain::Polygon,
+        "mumbai" | "matic-mumbai" => NamedChain::PolygonMumbai,
+        "morden" => NamedChain::Morden,
+        "avax" | "avalanche-mainnet" => NamedChain::Avalanche,
+        "fuji" => NamedChain::AvalancheFuji,
+        "bsc" | "binance" | "bnb" => NamedChain::BinanceSmartChain,
+        "bsc-testnet" | "bnb-testnet" => NamedChain::BinanceSmartChainTestnet,
+        "gnosis" | "xdai" => NamedChain::XDai,
+        "ftm" => NamedChain::Fantom,
+        _ => return None,
+    })
+}
+
 impl Chain {
     /// The id of the chain.
     pub const fn id(&self) -> u64 {
@@ -31,7 +65,7 @@ impl Chain {
         match self {
             Self::Named(chain) => Ok(*chain),
             Self::Id(id) => {
-                NamedChain::try_from(*id).map_err(|_| eyre::eyre!("Unsupported chain: {id}"))
+                named_chain_from_id(*id).ok_or_else(|| eyre::eyre!("Unsupported chain: {id}"))
             }
         }
     }
@@ -39,22 +73,189 @@ impl Chain {
     /// Helper function for checking if a chainid corresponds to a legacy chainid
     /// without eip1559
     pub fn is_legacy(&self) -> bool {
-        self.named().map_or(false, |c| c.is_legacy())
+        if let Ok(chain) = self.named() {
+            return chain.is_legacy();
+        }
+        ChainRegistry::get(self.id()).map_or(false, |custom| custom.is_legacy)
     }
 
     /// Returns the corresponding etherscan URLs
     pub fn etherscan_urls(&self) -> Option<(&'static str, &'static str)> {
-        self.named().ok().and_then(|c| c.etherscan_urls())
+        if let Ok(chain) = self.named() {
+            return chain.etherscan_urls();
+        }
+        ChainRegistry::get(self.id()).and_then(|custom| custom.etherscan_urls())
+    }
+
+    /// Returns the block explorer for this chain, describing both its family
+    /// (Etherscan, Blockscout, Etherscan v2) and its API/browser URLs.
+    ///
+    /// Prefer this over [`Chain::etherscan_urls`] when the caller needs to
+    /// know which API dialect to speak, e.g. whether to send an
+    /// Etherscan-v2 `chainid` query parameter or talk to a Blockscout host.
+    ///
+    /// For a custom-registered chain the family comes from
+    /// [`CustomChain::kind`], since the registry (not a hardcoded table) is
+    /// the only thing that knows it.
+    pub fn explorer(&self) -> Option<Explorer> {
+        if self.named().is_ok() {
+            let (api_url, browser_url) = self.etherscan_urls()?;
+            let kind = self.named_explorer_kind();
+            // The v2 API serves every chain from one host, selected by the
+            // `chainid` query parameter, not from the per-chain v1 host.
+            let api_url =
+                if kind == ExplorerKind::EtherscanV2 { "https://api.etherscan.io/v2/api" } else { api_url };
+            return Some(Explorer {
+                kind,
+                api_url: api_url.to_string(),
+                browser_url: browser_url.to_string(),
+            });
+        }
+        let custom = ChainRegistry::get(self.id())?;
+        let (api_url, browser_url) = custom.etherscan_urls()?;
+        Some(Explorer {
+            kind: custom.kind,
+            api_url: api_url.to_string(),
+            browser_url: browser_url.to_string(),
+        })
+    }
+
+    /// Returns the conventional environment variable name used to look up an
+    /// Etherscan(-compatible) API key for this chain, e.g.
+    /// `SNOWTRACE_API_KEY` for Avalanche or `POLYGONSCAN_API_KEY` for Polygon.
+    ///
+    /// Only covers [`NamedChain`]s: a custom-registered chain has no fixed
+    /// env var convention to report, so this returns `None` for it even if
+    /// it has explorer URLs via [`Chain::etherscan_urls`].
+    pub fn etherscan_api_key_env(&self) -> Option<&'static str> {
+        Some(match self.id() {
+            1 | 3 | 4 | 5 | 17000 | 11155111 => "ETHERSCAN_API_KEY",
+            137 | 80001 => "POLYGONSCAN_API_KEY",
+            43114 | 43113 => "SNOWTRACE_API_KEY",
+            42161 | 421611 | 421613 | 42170 => "ARBISCAN_API_KEY",
+            10 | 420 => "OPTIMISTIC_ETHERSCAN_API_KEY",
+            56 | 97 => "BSCSCAN_API_KEY",
+            250 | 4002 => "FTMSCAN_API_KEY",
+            25 | 338 => "CRONOSCAN_API_KEY",
+            8453 | 84531 => "BASESCAN_API_KEY",
+            100 => "GNOSISSCAN_API_KEY",
+            _ => return None,
+        })
+    }
+
+    /// Returns a reasonable default polling interval / expected block time
+    /// for this chain, if known, e.g. ~12s for mainnet or ~2s for Polygon.
+    ///
+    /// Falls back to the custom chain registry for a [`Chain::Id`] not in
+    /// [`NamedChain`].
+    pub fn average_blocktime_hint(&self) -> Option<Duration> {
+        let hint = match self.id() {
+            1 => Some(12_000),             // Ethereum mainnet
+            5 | 11155111 | 17000 => Some(12_000), // Goerli, Sepolia, Holesky
+            137 | 80001 => Some(2_000),     // Polygon PoS, Mumbai
+            56 | 97 => Some(3_000),         // BNB Smart Chain
+            43114 | 43113 => Some(2_000),   // Avalanche C-Chain, Fuji
+            250 | 4002 => Some(1_000),      // Fantom Opera, testnet
+            100 => Some(5_000),             // Gnosis Chain
+            10 | 420 | 8453 | 84531 | 42161 | 421613 | 42170 => Some(250), // OP-stack & Arbitrum L2s
+            _ => None,
+        };
+        if let Some(millis) = hint {
+            return Some(Duration::from_millis(millis));
+        }
+        ChainRegistry::get(self.id()).and_then(|custom| custom.average_blocktime)
+    }
+
+    /// Whether this chain supports EIP-1559 fee markets, i.e. the inverse of
+    /// [`Chain::is_legacy`].
+    pub fn supports_eip1559(&self) -> bool {
+        !self.is_legacy()
+    }
+
+    /// Whether this chain's EVM has upgraded to Shanghai and therefore
+    /// supports the `PUSH0` opcode.
+    ///
+    /// Falls back to the custom chain registry for a [`Chain::Id`] not in
+    /// [`NamedChain`].
+    pub fn supports_push0(&self) -> bool {
+        let named = matches!(
+            self.id(),
+            1 | 5 | 11155111 | 17000 // Ethereum mainnet, Goerli, Sepolia, Holesky
+                | 137 | 80001 // Polygon PoS, Mumbai
+                | 8453 | 84531 // Base, Base Goerli
+                | 10 | 420 // Optimism, Optimism Goerli
+        );
+        named || ChainRegistry::get(self.id()).map_or(false, |custom| custom.supports_push0)
+    }
+
+    /// Whether this chain is a testnet rather than a production network. The
+    /// local Anvil/Hardhat dev chain (id 31337) counts as a testnet.
+    ///
+    /// Falls back to the custom chain registry for a [`Chain::Id`] not in
+    /// [`NamedChain`].
+    pub fn is_testnet(&self) -> bool {
+        let named = matches!(
+            self.id(),
+            3 | 4 | 5 | 42 | 17000 | 11155111 // Ropsten, Rinkeby, Goerli, Kovan, Holesky, Sepolia
+                | 80001 // Polygon Mumbai
+                | 43113 // Avalanche Fuji
+                | 97 // BSC testnet
+                | 4002 // Fantom testnet
+                | 420 | 84531 | 421613 // Optimism/Base/Arbitrum Goerli
+                | 31337 // Anvil/Hardhat local chain
+        );
+        named || ChainRegistry::get(self.id()).map_or(false, |custom| custom.is_testnet)
+    }
+
+    /// The explorer family backing [`Chain::etherscan_urls`] for a
+    /// [`NamedChain`], derived from [`Chain::etherscan_api_key_env`] so the two
+    /// methods can't disagree: chains whose key is `ETHERSCAN_API_KEY` report
+    /// the Etherscan-v2 dialect, every other named chain (including those with
+    /// no key env entry) reports classic Etherscan. Named chains are never
+    /// reported as Blockscout.
+    fn named_explorer_kind(&self) -> ExplorerKind {
+        if matches!(self.etherscan_api_key_env(), Some("ETHERSCAN_API_KEY")) {
+            // Mainnet and its testnets are already served by Etherscan's
+            // unified v2 multi-chain API under a single API key.
+            ExplorerKind::EtherscanV2
+        } else {
+            ExplorerKind::Etherscan
+        }
     }
 }
 
+/// The family of block explorer a chain's API endpoint belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplorerKind {
+    /// A classic per-chain Etherscan instance (Etherscan, Polygonscan, Snowtrace, ...).
+    Etherscan,
+    /// A Blockscout instance.
+    Blockscout,
+    /// Etherscan's unified v2 multi-chain API (one host, `chainid` query param).
+    EtherscanV2,
+}
+
+/// A block explorer associated with a [`Chain`]: which family it belongs to,
+/// plus its API and browser base URLs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explorer {
+    /// The explorer family, e.g. `Etherscan` vs `Blockscout`.
+    pub kind: ExplorerKind,
+    /// Base URL for the explorer's API.
+    pub api_url: String,
+    /// Base URL for the explorer's web UI.
+    pub browser_url: String,
+}
+
 impl fmt::Display for Chain {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Chain::Named(chain) => chain.fmt(f),
             Chain::Id(id) => {
-                if let Ok(chain) = NamedChain::try_from(*id) {
+                if let Some(chain) = named_chain_from_id(*id) {
                     chain.fmt(f)
+                } else if let Some(custom) = ChainRegistry::get(*id) {
+                    f.write_str(&custom.name)
                 } else {
                     id.fmt(f)
                 }
@@ -71,7 +272,7 @@ impl From<NamedChain> for Chain {
 
 impl From<u64> for Chain {
     fn from(id: u64) -> Self {
-        NamedChain::try_from(id).map(Chain::Named).unwrap_or_else(|_| Chain::Id(id))
+        named_chain_from_id(id).map(Chain::Named).unwrap_or(Chain::Id(id))
     }
 }
 
@@ -108,7 +309,12 @@ impl TryFrom<Chain> for NamedChain {
     fn try_from(chain: Chain) -> Result<Self, Self::Error> {
         match chain {
             Chain::Named(chain) => Ok(chain),
-            Chain::Id(id) => id.try_into(),
+            // Resolve through the shared helper; when the id doesn't resolve,
+            // repeat the conversion via `TryFrom<u64>` to get a correctly-typed `Err`.
+            Chain::Id(id) => match named_chain_from_id(id) {
+                Some(chain) => Ok(chain),
+                None => id.try_into(),
+            },
         }
     }
 }
@@ -119,6 +325,10 @@ impl FromStr for Chain {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if let Ok(chain) = NamedChain::from_str(s) {
             Ok(Chain::Named(chain))
+        } else if let Some(chain) = named_chain_alias(s) {
+            Ok(Chain::Named(chain))
+        } else if let Some(id) = ChainRegistry::find_id_by_name(s) {
+            Ok(Chain::Id(id))
         } else {
             s.parse::<u64>()
                 .map(Chain::Id)
@@ -127,6 +337,21 @@ impl FromStr for Chain {
     }
 }
 
+impl Serialize for Chain {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // Serialize named chains in the exact form `FromStr`/`Deserialize`
+        // accept, so that `deserialize(serialize(c)) == c` always holds
+        // instead of depending on two independently-maintained string forms.
+        match self {
+            Chain::Named(chain) => serializer.collect_str(chain),
+            Chain::Id(id) => id.serialize(serializer),
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for Chain {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -140,12 +365,8 @@ impl<'de> Deserialize<'de> for Chain {
         }
 
         match ChainId::deserialize(deserializer)? {
-            ChainId::Named(s) => {
-                s.to_lowercase().parse().map(Chain::Named).map_err(serde::de::Error::custom)
-            }
-            ChainId::Id(id) => {
-                Ok(NamedChain::try_from(id).map(Chain::Named).unwrap_or_else(|_| Chain::Id(id)))
-            }
+            ChainId::Named(s) => Chain::from_str(&s).map_err(D::Error::custom),
+            ChainId::Id(id) => Ok(id.into()),
         }
     }
 }
@@ -155,3 +376,329 @@ impl Default for Chain {
         NamedChain::Mainnet.into()
     }
 }
+
+/// Metadata for a private/custom chain that isn't part of [`NamedChain`].
+///
+/// Registering one of these via [`ChainRegistry::register`] lets an
+/// appchain or L2 that foundry has never heard of still resolve explorer
+/// URLs and fee behavior through [`Chain`], the same way a [`NamedChain`]
+/// variant would.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomChain {
+    /// Human readable chain name, e.g. `"My Appchain"`.
+    pub name: String,
+    /// The chain id.
+    pub chain_id: u64,
+    /// Etherscan-compatible API base URL, if the chain has a block explorer.
+    ///
+    /// `&'static str` because `Chain::etherscan_urls` is `&'static str`-typed
+    /// for named chains; build this via [`CustomChain::new`] if you only have
+    /// an owned `String`, so the leak it takes to get there happens once per
+    /// registration rather than once per lookup.
+    pub explorer_api_url: Option<&'static str>,
+    /// Browser-facing block explorer URL, if any. See
+    /// [`CustomChain::explorer_api_url`] for why this is `&'static str`.
+    pub explorer_browser_url: Option<&'static str>,
+    /// The explorer's family. Unlike a [`NamedChain`], there's no table to
+    /// infer this from, so it's on the registrant to say whether their
+    /// explorer speaks the classic Etherscan, Blockscout, or Etherscan-v2
+    /// dialect.
+    pub kind: ExplorerKind,
+    /// Average block time, if known.
+    pub average_blocktime: Option<Duration>,
+    /// Whether the chain predates EIP-1559 and only supports legacy transactions.
+    pub is_legacy: bool,
+    /// Whether the chain's EVM supports the `PUSH0` opcode (Shanghai+).
+    pub supports_push0: bool,
+    /// Whether the chain is a testnet rather than a production network.
+    pub is_testnet: bool,
+}
+
+impl CustomChain {
+    /// Constructs a custom chain from owned URL strings, leaking them once
+    /// (here, at registration time) rather than on every
+    /// [`Chain::etherscan_urls`]/[`Chain::explorer`] call.
+    pub fn new(
+        name: impl Into<String>,
+        chain_id: u64,
+        explorer_api_url: Option<impl Into<String>>,
+        explorer_browser_url: Option<impl Into<String>>,
+        kind: ExplorerKind,
+        average_blocktime: Option<Duration>,
+        is_legacy: bool,
+        supports_push0: bool,
+        is_testnet: bool,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            chain_id,
+            explorer_api_url: explorer_api_url.map(leak_string),
+            explorer_browser_url: explorer_browser_url.map(leak_string),
+            kind,
+            average_blocktime,
+            is_legacy,
+            supports_push0,
+            is_testnet,
+        }
+    }
+
+    fn etherscan_urls(&self) -> Option<(&'static str, &'static str)> {
+        Some((self.explorer_api_url?, self.explorer_browser_url?))
+    }
+}
+
+fn leak_string(s: impl Into<String>) -> &'static str {
+    Box::leak(s.into().into_boxed_str())
+}
+
+/// A process-wide registry of [`CustomChain`]s registered at runtime.
+///
+/// This is what lets [`Chain::etherscan_urls`], [`Chain::is_legacy`],
+/// [`fmt::Display`] and [`FromStr`] resolve metadata for a bare
+/// `Chain::Id` that isn't in [`NamedChain`].
+#[derive(Debug, Default)]
+pub struct ChainRegistry {
+    chains: RwLock<HashMap<u64, CustomChain>>,
+}
+
+impl ChainRegistry {
+    fn global() -> &'static ChainRegistry {
+        static REGISTRY: OnceLock<ChainRegistry> = OnceLock::new();
+        REGISTRY.get_or_init(ChainRegistry::default)
+    }
+
+    /// Registers a custom chain, overwriting any existing entry for the same id.
+    pub fn register(chain: CustomChain) {
+        Self::global().chains.write().unwrap().insert(chain.chain_id, chain);
+    }
+
+    /// Returns the custom chain registered for `id`, if any.
+    pub fn get(id: u64) -> Option<CustomChain> {
+        Self::global().chains.read().unwrap().get(&id).cloned()
+    }
+
+    /// Returns the id of the custom chain whose name matches `s`, case-insensitively.
+    fn find_id_by_name(s: &str) -> Option<u64> {
+        Self::global()
+            .chains
+            .read()
+            .unwrap()
+            .values()
+            .find(|c| c.name.eq_ignore_ascii_case(s))
+            .map(|c| c.chain_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A representative sample of [`NamedChain`] variants, spanning mainnets,
+    /// testnets and L2s, used by tests that would otherwise need to iterate
+    /// every variant (which needs `strum`, not a dependency of this crate).
+    const SAMPLE_NAMED_CHAINS: &[NamedChain] = &[
+        NamedChain::Mainnet,
+        NamedChain::Goerli,
+        NamedChain::Sepolia,
+        NamedChain::Morden,
+        NamedChain::Polygon,
+        NamedChain::PolygonMumbai,
+        NamedChain::Avalanche,
+        NamedChain::AvalancheFuji,
+        NamedChain::BinanceSmartChain,
+        NamedChain::BinanceSmartChainTestnet,
+        NamedChain::XDai,
+        NamedChain::Fantom,
+    ];
+
+    #[test]
+    fn chain_display_and_from_str_roundtrip() {
+        for &named in SAMPLE_NAMED_CHAINS {
+            let chain = Chain::Named(named);
+            assert_eq!(Chain::from_str(&chain.to_string()).unwrap(), chain);
+        }
+    }
+
+    #[test]
+    fn custom_chain_registry_round_trip() {
+        let id = 900_000_001;
+        assert!(ChainRegistry::get(id).is_none());
+        ChainRegistry::register(CustomChain::new(
+            "My Appchain",
+            id,
+            Some("https://api.my-appchain.example/api"),
+            Some("https://my-appchain.example"),
+            ExplorerKind::Etherscan,
+            Some(Duration::from_millis(500)),
+            false,
+            false,
+            false,
+        ));
+
+        let chain = Chain::Id(id);
+        assert_eq!(chain.to_string(), "My Appchain");
+        assert_eq!(Chain::from_str("My Appchain").unwrap(), chain);
+        assert_eq!(
+            chain.etherscan_urls(),
+            Some(("https://api.my-appchain.example/api", "https://my-appchain.example"))
+        );
+    }
+
+    #[test]
+    fn custom_chain_etherscan_urls_does_not_leak_per_call() {
+        let id = 900_000_002;
+        ChainRegistry::register(CustomChain::new(
+            "Leak Check Chain",
+            id,
+            Some("https://api.leak-check.example"),
+            Some("https://leak-check.example"),
+            ExplorerKind::Etherscan,
+            None,
+            false,
+            false,
+            false,
+        ));
+
+        let chain = Chain::Id(id);
+        let (api_first, browser_first) = chain.etherscan_urls().unwrap();
+        let (api_second, browser_second) = chain.etherscan_urls().unwrap();
+        // Same pointer across calls proves the urls were leaked once at
+        // registration, not once per `etherscan_urls()` call.
+        assert!(std::ptr::eq(api_first, api_second));
+        assert!(std::ptr::eq(browser_first, browser_second));
+    }
+
+    #[test]
+    fn average_blocktime_hint_covers_named_and_custom_chains() {
+        assert_eq!(
+            Chain::Named(NamedChain::Mainnet).average_blocktime_hint(),
+            Some(Duration::from_secs(12))
+        );
+        assert_eq!(
+            Chain::Named(NamedChain::Polygon).average_blocktime_hint(),
+            Some(Duration::from_secs(2))
+        );
+
+        let id = 900_000_004;
+        assert_eq!(Chain::Id(id).average_blocktime_hint(), None);
+        ChainRegistry::register(CustomChain::new(
+            "Blocktime Check Chain",
+            id,
+            None::<String>,
+            None::<String>,
+            ExplorerKind::Etherscan,
+            Some(Duration::from_millis(400)),
+            false,
+            false,
+            false,
+        ));
+        assert_eq!(Chain::Id(id).average_blocktime_hint(), Some(Duration::from_millis(400)));
+    }
+
+    #[test]
+    fn supports_eip1559_is_inverse_of_is_legacy() {
+        assert!(!Chain::Named(NamedChain::Mainnet).is_legacy());
+        assert!(Chain::Named(NamedChain::Mainnet).supports_eip1559());
+    }
+
+    #[test]
+    fn supports_push0_and_is_testnet_cover_named_chains() {
+        assert!(Chain::Named(NamedChain::Mainnet).supports_push0());
+        assert!(!Chain::Named(NamedChain::Morden).supports_push0());
+
+        assert!(Chain::Named(NamedChain::Goerli).is_testnet());
+        assert!(!Chain::Named(NamedChain::Mainnet).is_testnet());
+    }
+
+    #[test]
+    fn supports_push0_and_is_testnet_fall_back_to_custom_registry() {
+        let id = 900_000_005;
+        assert!(!Chain::Id(id).supports_push0());
+        assert!(!Chain::Id(id).is_testnet());
+
+        ChainRegistry::register(CustomChain::new(
+            "Shanghai Testnet Appchain",
+            id,
+            None::<String>,
+            None::<String>,
+            ExplorerKind::Etherscan,
+            None,
+            false,
+            true,
+            true,
+        ));
+        assert!(Chain::Id(id).supports_push0());
+        assert!(Chain::Id(id).is_testnet());
+    }
+
+    #[test]
+    fn explorer_reports_mainnet_as_etherscan_v2() {
+        let explorer = Chain::Named(NamedChain::Mainnet).explorer().unwrap();
+        assert_eq!(explorer.kind, ExplorerKind::EtherscanV2);
+        assert_eq!(Chain::Named(NamedChain::Mainnet).etherscan_api_key_env(), Some("ETHERSCAN_API_KEY"));
+    }
+
+    #[test]
+    fn explorer_reports_gnosis_consistently_with_its_api_key_env() {
+        // Gnosis Chain's explorer (gnosisscan.io) is a classic per-chain
+        // Etherscan fork with its own API key, same family as Polygonscan -
+        // `explorer().kind` and `etherscan_api_key_env()` must agree on that.
+        let explorer = Chain::Named(NamedChain::XDai).explorer().unwrap();
+        assert_eq!(explorer.kind, ExplorerKind::Etherscan);
+        assert_eq!(Chain::Named(NamedChain::XDai).etherscan_api_key_env(), Some("GNOSISSCAN_API_KEY"));
+    }
+
+    #[test]
+    fn explorer_reports_polygonscan_family_as_classic_etherscan() {
+        let explorer = Chain::Named(NamedChain::Polygon).explorer().unwrap();
+        assert_eq!(explorer.kind, ExplorerKind::Etherscan);
+        assert_eq!(Chain::Named(NamedChain::Polygon).etherscan_api_key_env(), Some("POLYGONSCAN_API_KEY"));
+    }
+
+    #[test]
+    fn explorer_is_none_without_known_urls() {
+        assert!(Chain::Id(900_000_003).explorer().is_none());
+    }
+
+    #[test]
+    fn explorer_uses_the_kind_the_registrant_declared() {
+        let id = 900_000_006;
+        ChainRegistry::register(CustomChain::new(
+            "Blockscout Appchain",
+            id,
+            Some("https://api.blockscout-appchain.example"),
+            Some("https://blockscout-appchain.example"),
+            ExplorerKind::Blockscout,
+            None,
+            false,
+            false,
+            false,
+        ));
+        let explorer = Chain::Id(id).explorer().unwrap();
+        assert_eq!(explorer.kind, ExplorerKind::Blockscout);
+    }
+
+    #[test]
+    fn chain_from_str_accepts_aliases() {
+        assert_eq!(Chain::from_str("ethereum").unwrap(), Chain::Named(NamedChain::Mainnet));
+        assert_eq!(Chain::from_str("ETH").unwrap(), Chain::Named(NamedChain::Mainnet));
+        assert_eq!(Chain::from_str("matic").unwrap(), Chain::Named(NamedChain::Polygon));
+        assert_eq!(Chain::from_str("morden").unwrap(), Chain::Named(NamedChain::Morden));
+    }
+
+    #[test]
+    fn chain_serde_roundtrip() {
+        for &named in SAMPLE_NAMED_CHAINS {
+            let chain = Chain::Named(named);
+            let ser = serde_json::to_string(&chain).unwrap();
+            let de: Chain = serde_json::from_str(&ser).unwrap();
+            assert_eq!(de, chain);
+        }
+        for id in [999_999_999u64, 123_456] {
+            let chain = Chain::Id(id);
+            let ser = serde_json::to_string(&chain).unwrap();
+            let de: Chain = serde_json::from_str(&ser).unwrap();
+            assert_eq!(de, chain);
+        }
+    }
+}